@@ -0,0 +1,311 @@
+//! Compiles a locally-developed extension directory into a Wasm
+//! component and installs it into the running IDE, giving extension
+//! authors the fast inner loop the `HelloWorld` example currently lacks.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const WASI_ADAPTER_VERSION: &str = "25.0.0";
+
+/// A compiled extension component, ready to hand to the running IDE.
+#[derive(Debug, Clone)]
+pub struct ComponentBytes(pub Vec<u8>);
+
+/// Everything that can go wrong turning an extension directory into a
+/// loadable component. Returned instead of panicking so the IDE can show
+/// the author a precise error.
+#[derive(Debug)]
+pub enum BuildError {
+    /// `path` doesn't look like an extension crate (no `Cargo.toml`).
+    NotAnExtension { path: PathBuf },
+    /// The `wasm32-wasi` target isn't installed and `rustup target add`
+    /// failed.
+    MissingTarget { stderr: String },
+    /// `cargo build` for the extension crate failed.
+    Compile { stderr: String },
+    /// The wasi-preview1 adapter could not be downloaded or read from
+    /// the cache.
+    AdapterUnavailable { source: std::io::Error },
+    /// Turning the compiled core module into a component failed.
+    Componentize { stderr: String },
+    /// Writing the built component or symlinking it into the installed-
+    /// extensions directory failed.
+    Install { source: std::io::Error },
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::NotAnExtension { path } => {
+                write!(f, "{} does not contain a Cargo.toml", path.display())
+            }
+            BuildError::MissingTarget { stderr } => {
+                write!(f, "failed to install the wasm32-wasi target: {stderr}")
+            }
+            BuildError::Compile { stderr } => write!(f, "extension failed to compile: {stderr}"),
+            BuildError::AdapterUnavailable { source } => {
+                write!(f, "could not obtain the wasi-preview1 adapter: {source}")
+            }
+            BuildError::Componentize { stderr } => {
+                write!(f, "failed to package extension as a component: {stderr}")
+            }
+            BuildError::Install { source } => {
+                write!(f, "failed to install the built component locally: {source}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// Build the extension crate at `path` into a Wasm component, installing
+/// the `wasm32-wasi` target and downloading the wasi-preview1 adapter on
+/// demand. Build-time artifacts are cached under [`cache_dir`] keyed by
+/// [`WASI_ADAPTER_VERSION`] so repeat builds skip the download.
+pub fn build_extension(path: &Path) -> Result<ComponentBytes, BuildError> {
+    if !path.join("Cargo.toml").exists() {
+        return Err(BuildError::NotAnExtension {
+            path: path.to_path_buf(),
+        });
+    }
+
+    ensure_wasm_target()?;
+    let core_module = compile_core_module(path)?;
+    let adapter = cached_adapter()?;
+    componentize(&core_module, &adapter)
+}
+
+/// Build `path` and install the resulting component into the running
+/// IDE by symlinking it into [`installed_extensions_dir`], so edits to
+/// the extension take effect on the next activation without a full
+/// reinstall.
+pub fn install_local(path: &Path) -> Result<(), BuildError> {
+    let component = build_extension(path)?;
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "extension".to_string());
+
+    let built_path = cache_dir().join("built").join(format!("{name}.wasm"));
+    std::fs::create_dir_all(built_path.parent().unwrap())
+        .and_then(|()| std::fs::write(&built_path, &component.0))
+        .map_err(|source| BuildError::Install { source })?;
+
+    let link_path = installed_extensions_dir().join(&name);
+    std::fs::create_dir_all(link_path.parent().unwrap())
+        .map_err(|source| BuildError::Install { source })?;
+    let _ = std::fs::remove_file(&link_path);
+    symlink(&built_path, &link_path).map_err(|source| BuildError::Install { source })
+}
+
+fn ensure_wasm_target() -> Result<(), BuildError> {
+    let installed = Command::new("rustup")
+        .args(["target", "list", "--installed"])
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).contains("wasm32-wasi"))
+        .unwrap_or(false);
+
+    if installed {
+        return Ok(());
+    }
+
+    let output = Command::new("rustup")
+        .args(["target", "add", "wasm32-wasi"])
+        .output()
+        .map_err(|source| BuildError::MissingTarget {
+            stderr: source.to_string(),
+        })?;
+
+    if !output.status.success() {
+        return Err(BuildError::MissingTarget {
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        });
+    }
+
+    Ok(())
+}
+
+fn compile_core_module(path: &Path) -> Result<PathBuf, BuildError> {
+    let output = Command::new("cargo")
+        .args(["build", "--release", "--target", "wasm32-wasi"])
+        .current_dir(path)
+        .output()
+        .map_err(|source| BuildError::Compile {
+            stderr: source.to_string(),
+        })?;
+
+    if !output.status.success() {
+        return Err(BuildError::Compile {
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        });
+    }
+
+    first_wasm_in(&path.join("target/wasm32-wasi/release")).map_err(|source| {
+        BuildError::Compile {
+            stderr: source.to_string(),
+        }
+    })
+}
+
+fn first_wasm_in(dir: &Path) -> std::io::Result<PathBuf> {
+    std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .find(|path| path.extension().is_some_and(|ext| ext == "wasm"))
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "no .wasm build output"))
+}
+
+/// Path to the cached wasi-preview1 adapter, downloading it into
+/// [`cache_dir`] if this is the first build at [`WASI_ADAPTER_VERSION`].
+fn cached_adapter() -> Result<PathBuf, BuildError> {
+    let cached = cache_dir()
+        .join("wasi-adapters")
+        .join(format!("wasi_snapshot_preview1.reactor-{WASI_ADAPTER_VERSION}.wasm"));
+
+    if cached.exists() {
+        return Ok(cached);
+    }
+
+    std::fs::create_dir_all(cached.parent().unwrap())
+        .map_err(|source| BuildError::AdapterUnavailable { source })?;
+    download_adapter(&cached)?;
+    Ok(cached)
+}
+
+fn download_adapter(dest: &Path) -> Result<(), BuildError> {
+    let url = format!(
+        "https://github.com/bytecodealliance/wasmtime/releases/download/v{WASI_ADAPTER_VERSION}/wasi_snapshot_preview1.reactor.wasm"
+    );
+    let output = Command::new("curl")
+        .args(["-fsSL", "-o"])
+        .arg(dest)
+        .arg(&url)
+        .output()
+        .map_err(|source| BuildError::AdapterUnavailable { source })?;
+
+    if !output.status.success() {
+        return Err(BuildError::AdapterUnavailable {
+            source: std::io::Error::other(String::from_utf8_lossy(&output.stderr).into_owned()),
+        });
+    }
+
+    Ok(())
+}
+
+fn componentize(core_module: &Path, adapter: &Path) -> Result<ComponentBytes, BuildError> {
+    let output = Command::new("wasm-tools")
+        .args(["component", "new", "--adapt"])
+        .arg(adapter)
+        .arg(core_module)
+        .args(["-o", "-"])
+        .output()
+        .map_err(|source| BuildError::Componentize {
+            stderr: source.to_string(),
+        })?;
+
+    if !output.status.success() {
+        return Err(BuildError::Componentize {
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        });
+    }
+
+    Ok(ComponentBytes(output.stdout))
+}
+
+#[cfg(unix)]
+fn symlink(original: &Path, link: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(original, link)
+}
+
+#[cfg(windows)]
+fn symlink(original: &Path, link: &Path) -> std::io::Result<()> {
+    std::os::windows::fs::symlink_file(original, link)
+}
+
+/// Per-user support directory for cached build artifacts and installed
+/// extensions, analogous to cargo's own `~/.cargo`.
+fn support_dir() -> PathBuf {
+    let base = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+        .unwrap_or_else(|| PathBuf::from("."));
+    base.join("ide-extension")
+}
+
+fn cache_dir() -> PathBuf {
+    support_dir().join("cache")
+}
+
+fn installed_extensions_dir() -> PathBuf {
+    support_dir().join("installed-extensions")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_wasm_in_finds_the_only_wasm_file() {
+        let dir = std::env::temp_dir().join(format!(
+            "ide-extension-host-test-first-wasm-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("extension.d"), b"").unwrap();
+        std::fs::write(dir.join("extension.wasm"), b"\0asm").unwrap();
+
+        let found = first_wasm_in(&dir).unwrap();
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(found, dir.join("extension.wasm"));
+    }
+
+    #[test]
+    fn first_wasm_in_reports_not_found_when_no_wasm_file_is_present() {
+        let dir = std::env::temp_dir().join(format!(
+            "ide-extension-host-test-no-wasm-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("extension.d"), b"").unwrap();
+
+        let err = first_wasm_in(&dir).unwrap_err();
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn support_dir_prefers_xdg_data_home_over_home() {
+        // `support_dir`/`cache_dir` read process-wide env vars, so this is
+        // one test exercising every precedence case rather than several
+        // that could race each other under a parallel test runner; see
+        // the same pattern in `ide_extension::tracing`.
+        let original_xdg = std::env::var_os("XDG_DATA_HOME");
+        let original_home = std::env::var_os("HOME");
+
+        std::env::set_var("XDG_DATA_HOME", "/xdg-data");
+        std::env::set_var("HOME", "/home/someone");
+        assert_eq!(support_dir(), PathBuf::from("/xdg-data/ide-extension"));
+        assert_eq!(cache_dir(), PathBuf::from("/xdg-data/ide-extension/cache"));
+
+        std::env::remove_var("XDG_DATA_HOME");
+        assert_eq!(
+            support_dir(),
+            PathBuf::from("/home/someone/.local/share/ide-extension")
+        );
+
+        std::env::remove_var("HOME");
+        assert_eq!(support_dir(), PathBuf::from("./ide-extension"));
+
+        match original_xdg {
+            Some(value) => std::env::set_var("XDG_DATA_HOME", value),
+            None => std::env::remove_var("XDG_DATA_HOME"),
+        }
+        match original_home {
+            Some(value) => std::env::set_var("HOME", value),
+            None => std::env::remove_var("HOME"),
+        }
+    }
+}
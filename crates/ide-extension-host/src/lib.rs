@@ -0,0 +1,9 @@
+//! Host-side support for running and developing IDE extensions.
+
+pub mod builder;
+
+/// Re-exported from `ide-extension-runtime-core`, the `no_std` crate the
+/// execution-backend contract actually lives in so a backend like
+/// `ide-extension-runtime-micro` doesn't have to depend on this
+/// `std`-only crate (see that crate's module doc) to use it.
+pub use ide_extension_runtime_core::{runtime, version};
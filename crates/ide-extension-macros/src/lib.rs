@@ -0,0 +1,257 @@
+//! The `#[export]` / `export_extension!`, `#[min_api_version]`, and
+//! `#[trace]` attributes used to wire a type's trait `impl` blocks up to
+//! the host-facing bindings generated from `wit/extension.wit`.
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, FnArg, ItemFn, ItemImpl, LitStr, Pat, Path};
+
+/// Mark an `impl Extension`, `impl CommandHandler`,
+/// `impl LanguageServerProvider`, or `impl SlashCommandHandler` block as
+/// one of the extension's capability implementations. Besides asserting
+/// the trait bound at compile time, this generates the bridge `impl`
+/// that actually satisfies `wit-bindgen`'s generated `Guest` trait for
+/// the matching interface, forwarding each call to the hand-rolled trait
+/// above — `wit-bindgen`'s output has no idea `Extension` et al. exist,
+/// so something has to connect the two. Call [`export_extension!`]
+/// exactly once for the type, after all of its `#[export]` blocks, to
+/// wire `wit-bindgen`'s `export!` up against the `Guest` impls this
+/// attribute produced. `wit-bindgen` requires `export!` be invoked once
+/// per component for a type implementing every exported guest trait, so
+/// this attribute alone can't do that part without producing duplicate
+/// export symbols.
+#[proc_macro_attribute]
+pub fn export(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item_impl = parse_macro_input!(item as ItemImpl);
+
+    let self_ty = &item_impl.self_ty;
+    let trait_path = item_impl
+        .trait_
+        .as_ref()
+        .map(|(_, path, _)| path)
+        .expect("#[export] may only be used on `impl Trait for Type` blocks");
+    let trait_name = trait_path
+        .segments
+        .last()
+        .expect("trait path has at least one segment")
+        .ident
+        .to_string();
+
+    let bridge = guest_bridge(self_ty, &trait_name);
+
+    let expanded = quote! {
+        #item_impl
+
+        const _: fn() = || {
+            fn assert_impl<T: #trait_path>() {}
+            assert_impl::<#self_ty>();
+        };
+
+        #bridge
+    };
+
+    expanded.into()
+}
+
+/// The `impl Guest for #self_ty` bridging a hand-rolled capability trait
+/// (named `trait_name`) to the `wit-bindgen`-generated `Guest` trait for
+/// the matching WIT interface. Parameter and return types line up
+/// exactly with the hand-rolled traits in `ide_extension::lib` because
+/// those traits are themselves written in terms of the bindings' own
+/// re-exported types (see `ide_extension::{ActivationEvent, CommandArg,
+/// ...}`), so the bridge is a direct forward rather than a conversion.
+fn guest_bridge(self_ty: &syn::Type, trait_name: &str) -> proc_macro2::TokenStream {
+    match trait_name {
+        "Extension" => quote! {
+            impl ::ide_extension::bindings::exports::ide::extension::extension::Guest for #self_ty {
+                fn activate(event: ::ide_extension::ActivationEvent) -> Result<(), String> {
+                    <#self_ty as ::ide_extension::Extension>::activate(event)
+                }
+
+                fn deactivate() {
+                    <#self_ty as ::ide_extension::Extension>::deactivate()
+                }
+
+                fn capabilities() -> Vec<::ide_extension::Capability> {
+                    <#self_ty as ::ide_extension::Extension>::capabilities()
+                }
+            }
+        },
+        "CommandHandler" => quote! {
+            impl ::ide_extension::bindings::exports::ide::extension::command_handler::Guest for #self_ty {
+                fn handle_command(
+                    command_id: String,
+                    args: Vec<::ide_extension::CommandArg>,
+                ) -> Result<Option<::ide_extension::CommandArg>, String> {
+                    <#self_ty as ::ide_extension::CommandHandler>::handle_command(&command_id, args)
+                }
+            }
+        },
+        "LanguageServerProvider" => quote! {
+            impl ::ide_extension::bindings::exports::ide::extension::language_server_provider::Guest for #self_ty {
+                fn language_server_command(
+                    language_server_id: ::ide_extension::LanguageServerId,
+                    worktree: ::ide_extension::Worktree,
+                ) -> Result<::ide_extension::ServerCommand, String> {
+                    <#self_ty as ::ide_extension::LanguageServerProvider>::language_server_command(
+                        language_server_id,
+                        worktree,
+                    )
+                }
+
+                fn language_server_initialization_options(
+                    language_server_id: ::ide_extension::LanguageServerId,
+                    worktree: ::ide_extension::Worktree,
+                ) -> Result<Option<String>, String> {
+                    <#self_ty as ::ide_extension::LanguageServerProvider>::language_server_initialization_options(
+                        language_server_id,
+                        worktree,
+                    )
+                }
+
+                fn workspace_configuration(
+                    language_server_id: ::ide_extension::LanguageServerId,
+                    worktree: ::ide_extension::Worktree,
+                ) -> Result<Option<String>, String> {
+                    <#self_ty as ::ide_extension::LanguageServerProvider>::workspace_configuration(
+                        language_server_id,
+                        worktree,
+                    )
+                }
+            }
+        },
+        "SlashCommandHandler" => quote! {
+            impl ::ide_extension::bindings::exports::ide::extension::slash_command_handler::Guest for #self_ty {
+                fn run_slash_command(
+                    command_name: String,
+                    args: Vec<String>,
+                ) -> Result<::ide_extension::SlashCommandOutput, String> {
+                    let output = <#self_ty as ::ide_extension::SlashCommandHandler>::run_slash_command(&command_name, args)?;
+                    // No host in this repository re-checks a returned
+                    // `slash-command-output`'s sections, so this bridge is
+                    // the only thing standing between a handler and an
+                    // out-of-range section; check it here rather than
+                    // trusting every handler to have built its output
+                    // through `SlashCommandOutput::with_section`.
+                    ::ide_extension::commands::validate_slash_command_output(&output)?;
+                    Ok(output)
+                }
+
+                fn complete_argument(
+                    command_name: String,
+                    args: Vec<String>,
+                ) -> Result<Vec<::ide_extension::ArgumentCompletion>, String> {
+                    <#self_ty as ::ide_extension::SlashCommandHandler>::complete_argument(&command_name, args)
+                }
+            }
+        },
+        other => panic!(
+            "#[export] only bridges `impl {{Extension,CommandHandler,LanguageServerProvider,\
+             SlashCommandHandler}} for Type` blocks, found `impl {other} for Type`"
+        ),
+    }
+}
+
+/// Wire `wit-bindgen`'s `export!` up for `$Type`, which must already
+/// implement every capability trait it declared with `#[export]`. Call
+/// this exactly once per extension, typically at the bottom of the crate
+/// root after the `#[export]` impl blocks:
+///
+/// ```ignore
+/// ide_extension::export_extension!(HelloWorld);
+/// ```
+#[proc_macro]
+pub fn export_extension(item: TokenStream) -> TokenStream {
+    let self_ty = parse_macro_input!(item as Path);
+
+    quote! {
+        #[doc(hidden)]
+        ::ide_extension::bindings::export!(#self_ty with_types_in ::ide_extension::bindings);
+    }
+    .into()
+}
+
+/// Trace a method inside an `#[export] impl CommandHandler` block:
+/// emits a span named after the method on entry with its serialized
+/// arguments, and an event with the return value and elapsed time on
+/// exit. An argument whose `Debug` impl panics degrades to a
+/// placeholder rather than breaking the handler.
+#[proc_macro_attribute]
+pub fn trace(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut func = parse_macro_input!(item as ItemFn);
+    let name = func.sig.ident.to_string();
+
+    let arg_names: Vec<_> = func
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            FnArg::Typed(pat_type) => match &*pat_type.pat {
+                Pat::Ident(ident) => Some(ident.ident.clone()),
+                _ => None,
+            },
+            FnArg::Receiver(_) => None,
+        })
+        .collect();
+
+    let block = &func.block;
+    let traced_block: syn::Block = syn::parse_quote!({
+        // Argument serialization is the expensive part (a `Debug` format
+        // plus a `catch_unwind` per argument), so it only runs when the
+        // host actually wants to see it; `span`/`event` already no-op
+        // internally, but by then the formatting work is sunk cost.
+        let __ide_trace_span = if ::ide_extension::tracing::is_enabled(::ide_extension::tracing::Level::Info) {
+            let __ide_trace_args = [
+                #( ::ide_extension::tracing::serialize_arg(stringify!(#arg_names), &#arg_names) ),*
+            ];
+            Some(::ide_extension::tracing::span(&format!(
+                "{}({})",
+                #name,
+                __ide_trace_args.join(", "),
+            )))
+        } else {
+            None
+        };
+        let __ide_trace_start = ::std::time::Instant::now();
+        let __ide_trace_result = (move || #block)();
+        if let Some(__ide_trace_span) = &__ide_trace_span {
+            ::ide_extension::tracing::event(
+                __ide_trace_span,
+                ::ide_extension::tracing::Level::Info,
+                &format!("-> {:?} ({:?})", &__ide_trace_result, __ide_trace_start.elapsed()),
+            );
+        }
+        __ide_trace_result
+    });
+    *func.block = traced_block;
+
+    quote! { #func }.into()
+}
+
+/// Declare the minimum `ide_extension` API version an `impl Extension`
+/// block requires, e.g. `#[min_api_version("0.2.0")]`. The version is
+/// baked into a custom Wasm section so the host can read it before
+/// instantiating the component and refuse to load it with a clear error
+/// instead of trapping on an unsupported host call.
+#[proc_macro_attribute]
+pub fn min_api_version(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let version = parse_macro_input!(attr as LitStr).value();
+    let item_impl = parse_macro_input!(item as ItemImpl);
+
+    let bytes = syn::LitByteStr::new(version.as_bytes(), proc_macro2::Span::call_site());
+    let len = version.len();
+    let static_name = format_ident!(
+        "__IDE_EXTENSION_MIN_API_VERSION_{}",
+        version.replace(['.', '-'], "_")
+    );
+
+    let expanded = quote! {
+        #item_impl
+
+        #[link_section = "ide_extension_min_api_version"]
+        #[used]
+        static #static_name: [u8; #len] = *#bytes;
+    };
+
+    expanded.into()
+}
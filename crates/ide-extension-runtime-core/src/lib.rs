@@ -0,0 +1,16 @@
+#![cfg_attr(not(test), no_std)]
+//! Minimal `no_std` execution-backend contract: the [`runtime`] traits
+//! every [`runtime::ExtensionRuntime`] backend implements, and the
+//! [`version`] negotiation those backends run during instantiation.
+//!
+//! Lives in its own crate, not in `ide-extension-host`, because that
+//! crate's `builder` module shells out to `cargo`/`rustup`/`curl` and is
+//! unconditionally `std` — depending on it here would defeat the entire
+//! point of `ide-extension-runtime-micro` being an embeddable `no_std`
+//! backend. `ide-extension-host` depends on this crate and re-exports it
+//! as `ide_extension_host::{runtime, version}` for existing callers.
+
+extern crate alloc;
+
+pub mod runtime;
+pub mod version;
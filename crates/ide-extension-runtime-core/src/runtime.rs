@@ -0,0 +1,102 @@
+//! Pluggable execution backend for instantiating extension components.
+//!
+//! The standard doesn't mandate a particular Wasm engine: [`ExtensionRuntime`]
+//! lets a full-featured engine and the embeddable, `no_std` backend in
+//! the sibling `ide-extension-runtime-micro` crate (which validates
+//! modules but does not interpret their bytecode) sit behind the same
+//! interface, so embedding the host inside a constrained or sandboxed
+//! environment doesn't require pulling in either.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::fmt;
+
+/// Host-provided implementations of the import surface a component links
+/// against: `logging::info`, `commands::register_command`, and
+/// `notifications::show_info`. The command-dispatch entry point is
+/// [`Instance::handle_command`], not part of this trait.
+///
+/// `register_command` takes a bare command id rather than a JSON-encoded
+/// `CommandDefinition`: a backend that interprets the guest's real
+/// `register-command` WIT call (the full-featured engine, not
+/// `ide-extension-runtime-micro`) has the whole definition and is free
+/// to forward it however it likes to its own host-side registry; this
+/// trait only has to carry what every backend — including one that, like
+/// the micro runtime, discovers registrations from a build-time-recorded
+/// list of ids rather than by executing `activate` — can actually supply.
+pub trait HostImports {
+    fn log_info(&self, message: &str);
+    fn register_command(&self, command_id: &str) -> Result<(), String>;
+    fn show_info(&self, message: &str) -> Result<(), String>;
+}
+
+/// A running extension component.
+pub trait Instance {
+    /// Route a previously registered command to the instance's
+    /// `handle-command` export, with arguments and the result encoded as
+    /// JSON across the runtime boundary.
+    fn handle_command(
+        &mut self,
+        command_id: &str,
+        args_json: &str,
+    ) -> Result<Option<String>, RuntimeError>;
+}
+
+/// Everything that can prevent a component from being instantiated or
+/// keep it from running to completion.
+#[derive(Debug)]
+pub enum RuntimeError {
+    /// The module failed validation before any code ran.
+    InvalidModule(String),
+    /// The component's import surface isn't satisfied by this runtime's
+    /// [`HostImports`].
+    UnsupportedImport(String),
+    /// The component's `#[min_api_version]` requirement exceeds what
+    /// this host build understands; see [`crate::version::negotiate`].
+    UnsupportedApiVersion { extension_min: String, host: String },
+    /// Linear memory tried to grow, or a module declared a memory
+    /// maximum, past the configured cap.
+    MemoryLimitExceeded { requested_pages: u32, limit_pages: u32 },
+    /// Call stack depth exceeded the configured cap.
+    StackOverflow { limit: u32 },
+    /// The component trapped during execution.
+    Trap(String),
+}
+
+impl fmt::Display for RuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuntimeError::InvalidModule(reason) => write!(f, "invalid module: {reason}"),
+            RuntimeError::UnsupportedImport(name) => {
+                write!(f, "runtime does not provide import `{name}`")
+            }
+            RuntimeError::UnsupportedApiVersion { extension_min, host } => write!(
+                f,
+                "extension requires API version {extension_min} or newer, but this host only understands up to {host}"
+            ),
+            RuntimeError::MemoryLimitExceeded {
+                requested_pages,
+                limit_pages,
+            } => write!(
+                f,
+                "memory grow to {requested_pages} pages exceeds the {limit_pages}-page cap"
+            ),
+            RuntimeError::StackOverflow { limit } => {
+                write!(f, "call stack exceeded the {limit}-frame cap")
+            }
+            RuntimeError::Trap(reason) => write!(f, "trap: {reason}"),
+        }
+    }
+}
+
+impl core::error::Error for RuntimeError {}
+
+/// An execution backend capable of instantiating extension components.
+/// Implement this to plug in an engine other than the host's default one.
+pub trait ExtensionRuntime {
+    fn instantiate(
+        &self,
+        bytes: &[u8],
+        host: &dyn HostImports,
+    ) -> Result<Box<dyn Instance>, RuntimeError>;
+}
@@ -0,0 +1,125 @@
+//! Host-side counterpart to `#[min_api_version]`: picking the API
+//! version to route host calls through, and refusing to load an
+//! extension whose requirement the host can't meet.
+//!
+//! The host understands [`HOST_API_VERSION`], an extension (optionally)
+//! declares the lowest `ide_extension::version::API_VERSION` it was
+//! written against via `#[min_api_version]`. [`negotiate`] compares the
+//! two and, rather than letting an unsupported host call trap the
+//! component later, rejects the mismatch up front with
+//! [`RuntimeError::UnsupportedApiVersion`].
+
+use alloc::string::ToString;
+use core::cmp::Ordering;
+use core::fmt;
+
+use crate::runtime::RuntimeError;
+
+/// The highest API version this host build understands. Kept in sync
+/// with `ide_extension::version::API_VERSION` on the guest side; the two
+/// only need to agree on format (`major.minor.patch`), not on which
+/// crate owns the source of truth.
+pub const HOST_API_VERSION: &str = "0.3.0";
+
+/// A parsed `major.minor.patch` version, the only shape
+/// `#[min_api_version]` and [`HOST_API_VERSION`] ever take.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl Version {
+    pub fn parse(raw: &str) -> Option<Self> {
+        let mut parts = raw.trim().split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        let patch = parts.next()?.parse().ok()?;
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(Self { major, minor, patch })
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch).cmp(&(other.major, other.minor, other.patch))
+    }
+}
+
+/// Pick the version to route this extension instance's host calls
+/// through, refusing to load it if its `#[min_api_version]` requirement
+/// exceeds [`HOST_API_VERSION`]. `extension_min` is `None` for
+/// pre-negotiation extensions that never declared one, which negotiate
+/// against [`HOST_API_VERSION`] unconditionally.
+///
+/// Returns the negotiated version — the highest one both sides support,
+/// which today is just [`HOST_API_VERSION`] since the host doesn't yet
+/// support multiple API revisions side by side — rather than a bare
+/// `Ok(())`, so a future multi-version host has somewhere to plug in.
+pub fn negotiate(extension_min: Option<&str>) -> Result<Version, RuntimeError> {
+    let host = Version::parse(HOST_API_VERSION)
+        .unwrap_or_else(|| panic!("HOST_API_VERSION {HOST_API_VERSION:?} is not major.minor.patch"));
+
+    let Some(extension_min) = extension_min else {
+        return Ok(host);
+    };
+
+    let extension_min = Version::parse(extension_min).ok_or_else(|| {
+        RuntimeError::InvalidModule(alloc::format!(
+            "declared #[min_api_version] {extension_min:?} is not a major.minor.patch version"
+        ))
+    })?;
+
+    if extension_min > host {
+        return Err(RuntimeError::UnsupportedApiVersion {
+            extension_min: extension_min.to_string(),
+            host: host.to_string(),
+        });
+    }
+
+    Ok(host)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negotiate_with_no_declared_minimum_uses_host_version() {
+        let negotiated = negotiate(None).unwrap();
+        assert_eq!(negotiated.to_string(), HOST_API_VERSION);
+    }
+
+    #[test]
+    fn negotiate_accepts_minimum_at_or_below_host_version() {
+        assert!(negotiate(Some("0.1.0")).is_ok());
+        assert!(negotiate(Some(HOST_API_VERSION)).is_ok());
+    }
+
+    #[test]
+    fn negotiate_refuses_minimum_above_host_version() {
+        let err = negotiate(Some("9.0.0")).unwrap_err();
+        assert!(matches!(err, RuntimeError::UnsupportedApiVersion { .. }));
+    }
+
+    #[test]
+    fn negotiate_reports_unparseable_minimum_as_invalid_module() {
+        let err = negotiate(Some("not-a-version")).unwrap_err();
+        assert!(matches!(err, RuntimeError::InvalidModule(_)));
+    }
+}
@@ -0,0 +1,238 @@
+#![cfg_attr(not(test), no_std)]
+//! A minimal, `no_std`, no-`unsafe` [`ExtensionRuntime`] for embedding the
+//! host inside constrained or sandboxed environments (including
+//! Wasm-in-Wasm), where pulling in a full engine isn't an option. Only
+//! `alloc` is required.
+//!
+//! This backend validates a component's header, its declared
+//! `#[min_api_version]` against [`ide_extension_runtime_core::version`],
+//! its import section against the surface [`HostImports`] provides
+//! (`logging::info`, `commands::register_command`,
+//! `notifications::show_info`), and its declared memory limits against
+//! [`ResourceLimits::max_memory_pages`] before handing back an
+//! [`Instance`]. It does not interpret Wasm
+//! bytecode — running arbitrary guest control flow needs a real engine.
+//! Instead, an extension's build step records each command id it
+//! registers, together with the JSON result (if any) that command
+//! always produces, in an `ide_extension:commands` custom section
+//! (parsed by [`MicroRuntime::instantiate`], which calls
+//! [`HostImports::register_command`] for each one it finds); this is the
+//! only form of "execution" this backend can do, so it only suits
+//! commands whose output doesn't depend on their arguments or any
+//! state — anything that needs to branch on `args_json` needs the
+//! full-featured engine, as do extensions that need language servers,
+//! slash commands, or the UI primitives.
+//!
+//! There's deliberately no call-stack depth cap here: [`Instance::handle_command`]
+//! runs synchronously to completion with no recursion or re-entrancy
+//! path (a canned-result lookup can't call back into the runtime), so a
+//! depth limit would never trigger. A backend that actually executes
+//! guest code and can recurse should track and bound that itself.
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+
+use ide_extension_runtime_core::runtime::{ExtensionRuntime, HostImports, Instance, RuntimeError};
+use ide_extension_runtime_core::version;
+
+mod module;
+
+use module::parse_module;
+
+const SUPPORTED_IMPORTS: &[&str] = &[
+    "ide:extension/logging.info",
+    "ide:extension/commands.register-command",
+    "ide:extension/notifications.show-info",
+];
+
+/// Resource caps applied to every instance created by [`MicroRuntime`].
+#[derive(Debug, Clone, Copy)]
+pub struct ResourceLimits {
+    /// Maximum linear-memory size, in 64KiB pages, a module's declared
+    /// memory limits may request.
+    pub max_memory_pages: u32,
+}
+
+impl Default for ResourceLimits {
+    fn default() -> Self {
+        Self {
+            max_memory_pages: 256, // 16 MiB
+        }
+    }
+}
+
+/// The embeddable backend. It validates modules and serves the command
+/// results recorded at build time; it does not interpret Wasm bytecode.
+pub struct MicroRuntime {
+    limits: ResourceLimits,
+}
+
+impl MicroRuntime {
+    pub fn new(limits: ResourceLimits) -> Self {
+        Self { limits }
+    }
+}
+
+impl Default for MicroRuntime {
+    fn default() -> Self {
+        Self::new(ResourceLimits::default())
+    }
+}
+
+impl ExtensionRuntime for MicroRuntime {
+    fn instantiate(
+        &self,
+        bytes: &[u8],
+        host: &dyn HostImports,
+    ) -> Result<Box<dyn Instance>, RuntimeError> {
+        let module = parse_module(bytes)?;
+
+        // Refuse a component whose declared `#[min_api_version]` is
+        // newer than this host understands before any import or command
+        // registration runs, rather than letting a host call it doesn't
+        // know about trap later.
+        version::negotiate(module.min_api_version.as_deref())?;
+
+        for import in &module.imports {
+            if !SUPPORTED_IMPORTS.contains(&import.as_str()) {
+                return Err(RuntimeError::UnsupportedImport(import.clone()));
+            }
+        }
+
+        if let Some(declared_max) = module.memory_max_pages {
+            if declared_max > self.limits.max_memory_pages {
+                return Err(RuntimeError::MemoryLimitExceeded {
+                    requested_pages: declared_max,
+                    limit_pages: self.limits.max_memory_pages,
+                });
+            }
+        }
+
+        host.log_info(&format!(
+            "micro runtime: instantiated component with {} import(s), {} declared command(s)",
+            module.imports.len(),
+            module.commands.len(),
+        ));
+
+        let mut commands = BTreeMap::new();
+        for (command_id, result) in module.commands {
+            host.register_command(&command_id)
+                .map_err(RuntimeError::Trap)?;
+            commands.insert(command_id, result);
+        }
+
+        Ok(Box::new(MicroInstance { commands }))
+    }
+}
+
+struct MicroInstance {
+    commands: BTreeMap<String, Option<String>>,
+}
+
+impl Instance for MicroInstance {
+    fn handle_command(
+        &mut self,
+        command_id: &str,
+        _args_json: &str,
+    ) -> Result<Option<String>, RuntimeError> {
+        self.commands
+            .get(command_id)
+            .cloned()
+            .ok_or_else(|| RuntimeError::Trap(format!("unknown command: {command_id}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    struct NoopHost;
+
+    impl HostImports for NoopHost {
+        fn log_info(&self, _message: &str) {}
+        fn register_command(&self, _command_id: &str) -> Result<(), String> {
+            Ok(())
+        }
+        fn show_info(&self, _message: &str) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    fn leb128(mut value: u32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                bytes.push(byte);
+                break;
+            }
+            bytes.push(byte | 0x80);
+        }
+        bytes
+    }
+
+    fn name_bytes(name: &str) -> Vec<u8> {
+        let mut bytes = leb128(name.len() as u32);
+        bytes.extend_from_slice(name.as_bytes());
+        bytes
+    }
+
+    /// A minimal valid module declaring one command, with an optional
+    /// canned JSON result.
+    fn module_with_one_command(command_id: &str, result: Option<&str>) -> Vec<u8> {
+        let mut content = name_bytes("ide_extension:commands");
+        content.extend(leb128(1));
+        content.extend(name_bytes(command_id));
+        match result {
+            Some(json) => {
+                content.push(0x01);
+                content.extend(name_bytes(json));
+            }
+            None => content.push(0x00),
+        }
+
+        let mut bytes = alloc::vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+        bytes.push(0x00); // custom section
+        bytes.extend(leb128(content.len() as u32));
+        bytes.extend(content);
+        bytes
+    }
+
+    #[test]
+    fn handle_command_returns_the_recorded_result() {
+        let bytes = module_with_one_command("hello-world.say-hello", Some(r#"{"greeting":"hi"}"#));
+        let runtime = MicroRuntime::default();
+        let mut instance = runtime.instantiate(&bytes, &NoopHost).unwrap();
+
+        let result = instance.handle_command("hello-world.say-hello", "[]").unwrap();
+        assert_eq!(result, Some(String::from(r#"{"greeting":"hi"}"#)));
+    }
+
+    #[test]
+    fn handle_command_with_no_recorded_result_returns_none() {
+        let bytes = module_with_one_command("hello-world.say-hello", None);
+        let runtime = MicroRuntime::default();
+        let mut instance = runtime.instantiate(&bytes, &NoopHost).unwrap();
+
+        let result = instance.handle_command("hello-world.say-hello", "[]").unwrap();
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn handle_command_rejects_unregistered_command_id() {
+        let bytes = module_with_one_command("hello-world.say-hello", None);
+        let runtime = MicroRuntime::default();
+        let mut instance = runtime.instantiate(&bytes, &NoopHost).unwrap();
+
+        assert!(matches!(
+            instance.handle_command("not-registered", "[]"),
+            Err(RuntimeError::Trap(_))
+        ));
+    }
+}
@@ -0,0 +1,406 @@
+//! A deliberately narrow core-Wasm binary parser: just enough to read
+//! the import section (for [`SUPPORTED_IMPORTS`](crate::SUPPORTED_IMPORTS)
+//! validation), the memory section (for the resource cap), our own
+//! `ide_extension:commands` custom section, and the
+//! `ide_extension_min_api_version` custom section `#[min_api_version]`
+//! bakes in. It does not decode code bodies, types, or anything else the
+//! micro runtime doesn't need.
+//!
+//! Each entry in `ide_extension:commands` is a command id paired with the
+//! JSON result (if any) the build step recorded for it, so the micro
+//! runtime can actually return something for `handle-command` instead of
+//! just recognizing the id; see [`crate::MicroInstance`].
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use ide_extension_runtime_core::runtime::RuntimeError;
+
+const WASM_MAGIC: [u8; 4] = [0x00, 0x61, 0x73, 0x6d];
+const WASM_VERSION: [u8; 4] = [0x01, 0x00, 0x00, 0x00];
+
+const IMPORT_SECTION: u8 = 2;
+const MEMORY_SECTION: u8 = 5;
+const CUSTOM_SECTION: u8 = 0;
+const COMMANDS_SECTION_NAME: &str = "ide_extension:commands";
+const MIN_API_VERSION_SECTION_NAME: &str = "ide_extension_min_api_version";
+
+pub struct ParsedModule {
+    /// Dotted `module.field` names for every function/table/memory/global
+    /// import the module declares.
+    pub imports: Vec<String>,
+    /// The largest declared memory maximum across imported and local
+    /// memories, if any declared one.
+    pub memory_max_pages: Option<u32>,
+    /// Command ids and their recorded JSON result (if any) from the
+    /// `ide_extension:commands` custom section.
+    pub commands: Vec<(String, Option<String>)>,
+    /// The raw `#[min_api_version("...")]` string from the
+    /// `ide_extension_min_api_version` custom section, if the extension
+    /// declared one.
+    pub min_api_version: Option<String>,
+}
+
+pub fn parse_module(bytes: &[u8]) -> Result<ParsedModule, RuntimeError> {
+    if bytes.len() < 8 {
+        return Err(RuntimeError::InvalidModule(String::from(
+            "module shorter than the Wasm header",
+        )));
+    }
+    if bytes[0..4] != WASM_MAGIC {
+        return Err(RuntimeError::InvalidModule(String::from(
+            "missing `\\0asm` magic number",
+        )));
+    }
+    if bytes[4..8] != WASM_VERSION {
+        return Err(RuntimeError::InvalidModule(format!(
+            "unsupported Wasm version {:?}; the micro runtime only reads core Wasm 1.0 binaries",
+            &bytes[4..8]
+        )));
+    }
+
+    let mut reader = Reader::new(&bytes[8..]);
+    let mut imports = Vec::new();
+    let mut memory_max_pages = None;
+    let mut commands = Vec::new();
+    let mut min_api_version = None;
+
+    while reader.remaining() > 0 {
+        let section_id = reader.read_u8()?;
+        let section_len = reader.read_u32_leb()? as usize;
+        let mut section = Reader::new(reader.read_bytes(section_len)?);
+
+        match section_id {
+            IMPORT_SECTION => {
+                let count = section.read_u32_leb()?;
+                for _ in 0..count {
+                    let module_name = section.read_name()?;
+                    let field_name = section.read_name()?;
+                    if let Some(limits) = skip_import_desc(&mut section)? {
+                        memory_max_pages = max_option(memory_max_pages, limits.max);
+                    }
+                    imports.push(format!("{module_name}.{field_name}"));
+                }
+            }
+            MEMORY_SECTION => {
+                let count = section.read_u32_leb()?;
+                for _ in 0..count {
+                    let limits = read_limits(&mut section)?;
+                    memory_max_pages = max_option(memory_max_pages, limits.max);
+                }
+            }
+            CUSTOM_SECTION => {
+                let name = section.read_name()?;
+                if name == COMMANDS_SECTION_NAME {
+                    let count = section.read_u32_leb()?;
+                    for _ in 0..count {
+                        let command_id = section.read_name()?;
+                        let result = if section.read_u8()? != 0 {
+                            Some(section.read_name()?)
+                        } else {
+                            None
+                        };
+                        commands.push((command_id, result));
+                    }
+                } else if name == MIN_API_VERSION_SECTION_NAME {
+                    // `#[min_api_version]` bakes the version string in
+                    // directly via `#[link_section]`, with no
+                    // length-prefix of its own — it's the rest of the
+                    // custom section's payload verbatim.
+                    let version = section.read_remaining_utf8()?;
+                    min_api_version = Some(version);
+                }
+            }
+            _ => {
+                // Not needed for validation; the section's length already
+                // advanced `reader` past it above.
+            }
+        }
+    }
+
+    Ok(ParsedModule {
+        imports,
+        memory_max_pages,
+        commands,
+        min_api_version,
+    })
+}
+
+fn max_option(a: Option<u32>, b: Option<u32>) -> Option<u32> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        (a, None) => a,
+        (None, b) => b,
+    }
+}
+
+struct Limits {
+    max: Option<u32>,
+}
+
+fn read_limits(reader: &mut Reader) -> Result<Limits, RuntimeError> {
+    let flags = reader.read_u8()?;
+    let _min = reader.read_u32_leb()?;
+    let max = if flags & 0x01 != 0 {
+        Some(reader.read_u32_leb()?)
+    } else {
+        None
+    };
+    Ok(Limits { max })
+}
+
+/// Consumes an import's type-specific payload, returning its memory
+/// limits if it was a memory import.
+fn skip_import_desc(reader: &mut Reader) -> Result<Option<Limits>, RuntimeError> {
+    match reader.read_u8()? {
+        0x00 => {
+            // func: typeidx
+            reader.read_u32_leb()?;
+            Ok(None)
+        }
+        0x01 => {
+            // table: elemtype + limits
+            reader.read_u8()?;
+            read_limits(reader)?;
+            Ok(None)
+        }
+        0x02 => Ok(Some(read_limits(reader)?)), // memory: limits
+        0x03 => {
+            // global: valtype + mutability
+            reader.read_u8()?;
+            reader.read_u8()?;
+            Ok(None)
+        }
+        other => Err(RuntimeError::InvalidModule(format!(
+            "unknown import kind byte 0x{other:02x}"
+        ))),
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn remaining(&self) -> usize {
+        self.bytes.len() - self.pos
+    }
+
+    fn read_u8(&mut self) -> Result<u8, RuntimeError> {
+        let byte = *self
+            .bytes
+            .get(self.pos)
+            .ok_or_else(|| RuntimeError::InvalidModule(String::from("unexpected end of section")))?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], RuntimeError> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .ok_or_else(|| RuntimeError::InvalidModule(String::from("section length overflow")))?;
+        let slice = self
+            .bytes
+            .get(self.pos..end)
+            .ok_or_else(|| RuntimeError::InvalidModule(String::from("section runs past end of module")))?;
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn read_u32_leb(&mut self) -> Result<u32, RuntimeError> {
+        let mut result: u32 = 0;
+        let mut shift = 0u32;
+        loop {
+            let byte = self.read_u8()?;
+            result |= ((byte & 0x7f) as u32) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+            shift += 7;
+            if shift >= 35 {
+                return Err(RuntimeError::InvalidModule(String::from(
+                    "LEB128 varint too large",
+                )));
+            }
+        }
+    }
+
+    /// Consumes and UTF-8-decodes everything left in this reader, for a
+    /// payload with no length prefix of its own (the rest of the section
+    /// is the whole value).
+    fn read_remaining_utf8(&mut self) -> Result<String, RuntimeError> {
+        let remaining = self.remaining();
+        let bytes = self.read_bytes(remaining)?;
+        core::str::from_utf8(bytes)
+            .map(String::from)
+            .map_err(|_| RuntimeError::InvalidModule(String::from("section payload is not valid utf-8")))
+    }
+
+    fn read_name(&mut self) -> Result<String, RuntimeError> {
+        let len = self.read_u32_leb()? as usize;
+        let bytes = self.read_bytes(len)?;
+        core::str::from_utf8(bytes)
+            .map(String::from)
+            .map_err(|_| RuntimeError::InvalidModule(String::from("name is not valid utf-8")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leb128(mut value: u32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                bytes.push(byte);
+                break;
+            }
+            bytes.push(byte | 0x80);
+        }
+        bytes
+    }
+
+    fn name_bytes(name: &str) -> Vec<u8> {
+        let mut bytes = leb128(name.len() as u32);
+        bytes.extend_from_slice(name.as_bytes());
+        bytes
+    }
+
+    fn section(id: u8, content: Vec<u8>) -> Vec<u8> {
+        let mut bytes = alloc::vec![id];
+        bytes.extend(leb128(content.len() as u32));
+        bytes.extend(content);
+        bytes
+    }
+
+    fn module_bytes(sections: Vec<u8>) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&WASM_MAGIC);
+        bytes.extend_from_slice(&WASM_VERSION);
+        bytes.extend(sections);
+        bytes
+    }
+
+    #[test]
+    fn rejects_input_shorter_than_header() {
+        assert!(matches!(
+            parse_module(&[0x00, 0x61, 0x73]),
+            Err(RuntimeError::InvalidModule(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_wrong_magic() {
+        let mut bytes = module_bytes(Vec::new());
+        bytes[0] = 0xff;
+        assert!(matches!(
+            parse_module(&bytes),
+            Err(RuntimeError::InvalidModule(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_unsupported_version() {
+        let mut bytes = module_bytes(Vec::new());
+        bytes[4] = 0x02;
+        assert!(matches!(
+            parse_module(&bytes),
+            Err(RuntimeError::InvalidModule(_))
+        ));
+    }
+
+    #[test]
+    fn parses_memory_import_limits() {
+        let mut import = name_bytes("env");
+        import.extend(name_bytes("memory"));
+        import.push(0x02); // memory import kind
+        import.push(0x01); // limits flags: has max
+        import.extend(leb128(1)); // min
+        import.extend(leb128(4)); // max
+
+        let mut content = leb128(1); // one import
+        content.extend(import);
+
+        let bytes = module_bytes(section(IMPORT_SECTION, content));
+        let module = parse_module(&bytes).unwrap();
+
+        assert_eq!(module.imports, alloc::vec![String::from("env.memory")]);
+        assert_eq!(module.memory_max_pages, Some(4));
+    }
+
+    #[test]
+    fn parses_commands_custom_section() {
+        let mut content = name_bytes(COMMANDS_SECTION_NAME);
+        content.extend(leb128(2));
+        content.extend(name_bytes("a.cmd"));
+        content.push(0x00); // no recorded result
+        content.extend(name_bytes("b.cmd"));
+        content.push(0x01); // has a recorded result
+        content.extend(name_bytes(r#"{"ok":true}"#));
+
+        let bytes = module_bytes(section(CUSTOM_SECTION, content));
+        let module = parse_module(&bytes).unwrap();
+
+        assert_eq!(
+            module.commands,
+            alloc::vec![
+                (String::from("a.cmd"), None),
+                (String::from("b.cmd"), Some(String::from(r#"{"ok":true}"#))),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_min_api_version_custom_section() {
+        let mut content = name_bytes(MIN_API_VERSION_SECTION_NAME);
+        content.extend_from_slice(b"0.2.0");
+
+        let bytes = module_bytes(section(CUSTOM_SECTION, content));
+        let module = parse_module(&bytes).unwrap();
+
+        assert_eq!(module.min_api_version, Some(String::from("0.2.0")));
+    }
+
+    #[test]
+    fn rejects_leb128_that_never_terminates() {
+        // Six continuation bytes exceed the 35-bit shift cap a u32 LEB128
+        // varint can legally need.
+        let bytes = module_bytes(alloc::vec![
+            CUSTOM_SECTION,
+            0x80,
+            0x80,
+            0x80,
+            0x80,
+            0x80,
+            0x80,
+            0x01,
+        ]);
+        assert!(matches!(
+            parse_module(&bytes),
+            Err(RuntimeError::InvalidModule(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_section_length_past_end_of_module() {
+        // Declares a section 100 bytes long with none of it actually
+        // present.
+        let mut bytes = module_bytes(Vec::new());
+        bytes.push(CUSTOM_SECTION);
+        bytes.extend(leb128(100));
+        assert!(matches!(
+            parse_module(&bytes),
+            Err(RuntimeError::InvalidModule(_))
+        ));
+    }
+}
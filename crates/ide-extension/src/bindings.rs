@@ -0,0 +1,45 @@
+//! Raw bindings generated from `wit/extension.wit`. Do not edit by hand;
+//! everything extension authors touch is re-exported through
+//! [`crate::prelude`] instead.
+//!
+//! Which world gets generated — and so which of the optional capability
+//! groups an extension is required to implement a `Guest` trait for —
+//! is chosen by the `language-server` / `slash-commands` Cargo features.
+//! A command-only extension like `examples/hello-world` enables neither
+//! and only ever has to satisfy `host-core`'s `extension` and
+//! `command-handler` exports.
+//!
+//! `pub_export_macro: true` is required on every one of these: without
+//! it, wit-bindgen emits the generated `export!` macro as `pub(crate)`,
+//! usable only from inside this crate. `ide_extension_macros::export_extension!`
+//! expands to `::ide_extension::bindings::export!(...)` from the
+//! *downstream* extension crate (e.g. `examples/hello-world`), so the
+//! macro has to actually be reachable from there.
+
+#[cfg(not(any(feature = "language-server", feature = "slash-commands")))]
+wit_bindgen::generate!({
+    world: "host-core",
+    path: "../../wit",
+    pub_export_macro: true,
+});
+
+#[cfg(all(feature = "language-server", not(feature = "slash-commands")))]
+wit_bindgen::generate!({
+    world: "host-with-language-server",
+    path: "../../wit",
+    pub_export_macro: true,
+});
+
+#[cfg(all(feature = "slash-commands", not(feature = "language-server")))]
+wit_bindgen::generate!({
+    world: "host-with-slash-commands",
+    path: "../../wit",
+    pub_export_macro: true,
+});
+
+#[cfg(all(feature = "language-server", feature = "slash-commands"))]
+wit_bindgen::generate!({
+    world: "host-full",
+    path: "../../wit",
+    pub_export_macro: true,
+});
@@ -0,0 +1,141 @@
+//! Command and slash-command registration, and the [`CommandArg`] /
+//! [`SlashCommandOutput`] payload types.
+
+use crate::bindings::ide::extension::commands;
+pub use crate::bindings::ide::extension::slash_command_types::{
+    ArgumentCompletion, SlashCommandDefinition, SlashCommandOutput, SlashCommandSection,
+};
+pub use crate::bindings::ide::extension::types::{CommandArg, CommandDefinition};
+
+/// Register a command that will appear in the IDE's command palette.
+///
+/// Must be called from [`Extension::activate`](crate::Extension::activate);
+/// the registered command id is later routed to
+/// [`CommandHandler::handle_command`](crate::CommandHandler::handle_command).
+pub fn register_command(definition: CommandDefinition) -> Result<(), String> {
+    commands::register_command(&definition)
+}
+
+/// Register an AI-assistant slash command.
+///
+/// Routed to
+/// [`SlashCommandHandler::run_slash_command`](crate::SlashCommandHandler::run_slash_command)
+/// when invoked, and to
+/// [`SlashCommandHandler::complete_argument`](crate::SlashCommandHandler::complete_argument)
+/// while the user is still typing its argument.
+pub fn register_slash_command(definition: SlashCommandDefinition) -> Result<(), String> {
+    commands::register_slash_command(&definition)
+}
+
+impl SlashCommandOutput {
+    /// Start building an output with no sections yet.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            sections: Vec::new(),
+        }
+    }
+
+    /// Append a labeled section covering `range`, a byte range into
+    /// `self.text`. Checks the same in-bounds invariant
+    /// [`validate_slash_command_output`] does, so a mistake here surfaces
+    /// as a clear error immediately rather than silently building an
+    /// out-of-bounds section.
+    pub fn with_section(
+        mut self,
+        range: (u32, u32),
+        label: impl Into<String>,
+    ) -> Result<Self, String> {
+        let (start, end) = range;
+        if start > end || end as usize > self.text.len() {
+            return Err(format!(
+                "section range {start}..{end} is out of bounds for a {}-byte output",
+                self.text.len()
+            ));
+        }
+        self.sections.push(SlashCommandSection {
+            range,
+            label: label.into(),
+        });
+        Ok(self)
+    }
+}
+
+/// Check that every section in `output` is in-bounds for `output.text`,
+/// the same invariant [`SlashCommandOutput::with_section`] enforces when
+/// building one section at a time. A handler can still construct
+/// `SlashCommandOutput` by hand and skip `with_section` entirely — its
+/// fields are public — so the `#[export] impl SlashCommandHandler`
+/// bridge calls this on the returned value before it crosses the WIT
+/// boundary, rather than trusting `with_section` was used.
+///
+/// This is a guest-SDK convenience, not a host-enforced invariant: it
+/// runs inside the extension's own Wasm binary, compiled in by the
+/// `#[export]` bridge, before the value ever reaches the host. A
+/// component that implements the raw `Guest` trait directly instead of
+/// going through `#[export]` bypasses it entirely, and no host in this
+/// repository re-checks a returned `SlashCommandOutput` on its side.
+pub fn validate_slash_command_output(output: &SlashCommandOutput) -> Result<(), String> {
+    for section in &output.sections {
+        let (start, end) = section.range;
+        if start > end || end as usize > output.text.len() {
+            return Err(format!(
+                "section \"{}\" range {start}..{end} is out of bounds for a {}-byte output",
+                section.label,
+                output.text.len()
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn section(range: (u32, u32)) -> SlashCommandSection {
+        SlashCommandSection {
+            range,
+            label: "section".to_string(),
+        }
+    }
+
+    #[test]
+    fn with_section_accepts_in_bounds_range() {
+        let output = SlashCommandOutput::new("hello world").with_section((0, 5), "greeting");
+        assert!(output.is_ok());
+    }
+
+    #[test]
+    fn with_section_rejects_end_past_text_len() {
+        let output = SlashCommandOutput::new("hello").with_section((0, 6), "oops");
+        assert!(output.is_err());
+    }
+
+    #[test]
+    fn with_section_rejects_start_after_end() {
+        let output = SlashCommandOutput::new("hello").with_section((3, 1), "oops");
+        assert!(output.is_err());
+    }
+
+    #[test]
+    fn validate_accepts_output_with_no_sections() {
+        let output = SlashCommandOutput {
+            text: "hello".to_string(),
+            sections: Vec::new(),
+        };
+        assert!(validate_slash_command_output(&output).is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_hand_built_output_bypassing_with_section() {
+        // Constructed directly rather than through `with_section`, since
+        // the fields are public and nothing stops a handler from doing
+        // exactly this.
+        let output = SlashCommandOutput {
+            text: "hi".to_string(),
+            sections: vec![section((0, 10))],
+        };
+        assert!(validate_slash_command_output(&output).is_err());
+    }
+}
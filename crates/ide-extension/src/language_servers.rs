@@ -0,0 +1,20 @@
+//! Language server registration, mirroring the command-registration flow
+//! in [`crate::commands`].
+
+use crate::bindings::ide::extension::language_servers;
+// `language-server-types` is only generated when the `language-server`
+// feature selects a world that exports `language-server-provider` (the
+// only interface that `use`s it); see `crate::bindings`. The plain
+// `register-server` call below doesn't need it and is available in
+// every world.
+#[cfg(feature = "language-server")]
+pub use crate::bindings::ide::extension::language_server_types::{
+    LanguageServerId, ServerCommand, Worktree,
+};
+
+/// Register a language server that the host may spawn for the given
+/// language ids. May be called more than once from `activate` so one
+/// extension can provide several servers.
+pub fn register_server(language_server_id: &str, language_ids: Vec<String>) -> Result<(), String> {
+    language_servers::register_server(language_server_id, &language_ids)
+}
@@ -0,0 +1,119 @@
+//! # IDE Extension Standard
+//!
+//! Guest-side Rust API for building WebAssembly IDE extensions. This
+//! crate wraps the bindings generated from `wit/extension.wit` in an
+//! ergonomic, hand-written layer so extension authors never touch raw
+//! `wit-bindgen` output directly; see [`prelude`] for the recommended
+//! glob import.
+
+#[doc(hidden)]
+pub mod bindings;
+
+pub mod commands;
+pub mod language_servers;
+pub mod logging;
+pub mod notifications;
+pub mod prelude;
+pub mod tracing;
+pub mod ui;
+pub mod version;
+
+pub use bindings::ide::extension::types::{ActivationEvent, Capability};
+pub use commands::{ArgumentCompletion, CommandArg, SlashCommandOutput};
+#[cfg(feature = "language-server")]
+pub use language_servers::{LanguageServerId, ServerCommand, Worktree};
+pub use ide_extension_macros::{export, export_extension, min_api_version, trace};
+
+/// Implemented by the extension's root type to hook into the IDE's
+/// activation lifecycle.
+pub trait Extension: Sized {
+    /// Called once when the host loads the extension.
+    fn activate(event: ActivationEvent) -> Result<(), String>;
+
+    /// Called when the host unloads the extension.
+    fn deactivate();
+
+    /// Capability groups this extension targets, surfaced to the host
+    /// alongside [`crate::version::api_version`] for diagnostics. Which
+    /// `language-server-provider` / `slash-command-handler` exports the
+    /// component actually has is fixed at build time by the
+    /// `language-server` / `slash-commands` Cargo features (component
+    /// exports can't be made conditional at instantiation time) and not
+    /// renegotiable per instance. No host in this repository calls this
+    /// export today — `ide_extension_runtime_core::version::negotiate`
+    /// only compares the whole extension's declared minimum API version,
+    /// once, before `activate`, and refuses the whole component rather
+    /// than admitting it with some exports missing — so this is purely
+    /// informational until a host actually reads it. Defaults to empty.
+    fn capabilities() -> Vec<Capability> {
+        Vec::new()
+    }
+}
+
+/// Implemented by extensions that registered at least one command via
+/// [`commands::register_command`].
+pub trait CommandHandler: Sized {
+    /// Handle execution of a previously registered command.
+    fn handle_command(
+        command_id: &str,
+        args: Vec<CommandArg>,
+    ) -> Result<Option<CommandArg>, String>;
+}
+
+/// Implemented by extensions that registered at least one slash command
+/// via [`commands::register_slash_command`].
+pub trait SlashCommandHandler: Sized {
+    /// Run `command_name` with the given arguments, returning the text
+    /// and labeled sections to show the user.
+    fn run_slash_command(
+        command_name: &str,
+        args: Vec<String>,
+    ) -> Result<SlashCommandOutput, String>;
+
+    /// Offer completions for the argument the user is currently typing.
+    /// Defaults to no completions.
+    fn complete_argument(
+        command_name: &str,
+        args: Vec<String>,
+    ) -> Result<Vec<ArgumentCompletion>, String> {
+        let _ = (command_name, args);
+        Ok(Vec::new())
+    }
+}
+
+/// Implemented by extensions that provide a language server to the IDE,
+/// mirroring [`CommandHandler`] for the language-intelligence surface.
+/// Register the server ids this extension offers from `activate` with
+/// [`language_servers::register_server`]. Only available with the
+/// `language-server` feature, which is what selects a `bindings` world
+/// that actually generates `LanguageServerId`/`Worktree`/`ServerCommand`
+/// (see `crate::bindings`).
+#[cfg(feature = "language-server")]
+pub trait LanguageServerProvider: Sized {
+    /// Return the executable, arguments, and environment needed to spawn
+    /// `language_server_id` inside `worktree`.
+    fn language_server_command(
+        language_server_id: LanguageServerId,
+        worktree: Worktree,
+    ) -> Result<ServerCommand, String>;
+
+    /// JSON sent as `initializationOptions` on the server's `initialize`
+    /// request. Defaults to none.
+    fn language_server_initialization_options(
+        language_server_id: LanguageServerId,
+        worktree: Worktree,
+    ) -> Result<Option<String>, String> {
+        let _ = (language_server_id, worktree);
+        Ok(None)
+    }
+
+    /// JSON returned for the server's `workspace/configuration` requests.
+    /// Defaults to none.
+    fn workspace_configuration(
+        language_server_id: LanguageServerId,
+        worktree: Worktree,
+    ) -> Result<Option<String>, String> {
+        let _ = (language_server_id, worktree);
+        Ok(None)
+    }
+}
@@ -0,0 +1,8 @@
+//! Structured logging that shows up in the IDE's extension output panel.
+
+use crate::bindings::ide::extension::logging;
+
+/// Log an informational message.
+pub fn info(message: &str) {
+    logging::info(message)
+}
@@ -0,0 +1,19 @@
+//! Notifications shown to the user, from a one-shot toast to a
+//! long-running progress indicator.
+
+use crate::bindings::ide::extension::notifications;
+pub use crate::bindings::ide::extension::notifications::ProgressToken;
+
+/// Show an informational toast notification.
+pub fn show_info(message: &str) -> Result<(), String> {
+    notifications::show_info(message)
+}
+
+/// Show a progress notification and return a token to update it.
+///
+/// Call [`ProgressToken::report`] as the operation advances and
+/// [`ProgressToken::finish`] when it completes; the host dismisses the
+/// notification if the token is dropped without a `finish` call.
+pub fn show_progress(title: &str) -> Result<ProgressToken, String> {
+    notifications::show_progress(title)
+}
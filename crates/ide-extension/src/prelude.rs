@@ -0,0 +1,15 @@
+//! Convenience re-exports for extension authors.
+//!
+//! `use ide_extension::prelude::*;` brings the traits, macros, and data
+//! types needed by nearly every extension into scope.
+
+pub use crate::{commands, language_servers, logging, notifications, tracing, ui, version};
+pub use crate::{ActivationEvent, ArgumentCompletion, Capability, CommandArg, SlashCommandOutput};
+#[cfg(feature = "language-server")]
+pub use crate::{LanguageServerId, ServerCommand, Worktree};
+pub use crate::{CommandHandler, Extension, SlashCommandHandler};
+#[cfg(feature = "language-server")]
+pub use crate::LanguageServerProvider;
+pub use crate::commands::{CommandDefinition, SlashCommandDefinition};
+pub use crate::version::api_version;
+pub use ide_extension_macros::{export, export_extension, min_api_version, trace};
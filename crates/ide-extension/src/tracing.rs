@@ -0,0 +1,93 @@
+//! Opt-in structured execution tracing: the alternative to scattering
+//! `logging::info` calls through a handler.
+//!
+//! Most extension authors never call this module directly — annotate a
+//! method inside an `#[export] impl CommandHandler` with `#[trace]` and
+//! the attribute emits the [`span`]/[`event`] calls for you. Tracing
+//! costs nothing until [`set_level`] is called with something other than
+//! [`Level::Off`], which an extension typically does once in `activate`
+//! from [`ActivationEvent::tracing_level`](crate::ActivationEvent).
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::bindings::ide::extension::tracing;
+pub use crate::bindings::ide::extension::types::TracingLevel as Level;
+
+static CURRENT_LEVEL: AtomicU8 = AtomicU8::new(Level::Off as u8);
+
+/// Set the verbosity for this instance. Defaults to [`Level::Off`].
+pub fn set_level(level: Level) {
+    CURRENT_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Whether an event at `level` would actually reach the host given the
+/// level set by [`set_level`]. The `#[trace]` macro checks this before
+/// doing any argument serialization, so the "off" path costs nothing
+/// beyond this one atomic load.
+pub fn is_enabled(level: Level) -> bool {
+    level as u8 <= CURRENT_LEVEL.load(Ordering::Relaxed)
+}
+
+/// An open span, closed when dropped.
+pub struct Span(Option<u64>);
+
+/// Begin a span at [`Level::Info`]; a no-op, zero-cost handle if tracing
+/// is off. The host renders spans as rows in the extension's tracing
+/// panel for the lifetime of the returned [`Span`].
+pub fn span(name: &str) -> Span {
+    Span(is_enabled(Level::Info).then(|| tracing::span(name)))
+}
+
+/// Emit a single event scoped to `span`; a no-op if `level` is above the
+/// configured verbosity or the span itself is a no-op.
+pub fn event(span: &Span, level: Level, message: &str) {
+    if let Some(id) = span.0 {
+        if is_enabled(level) {
+            tracing::event(id, level, message);
+        }
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if let Some(id) = self.0 {
+            tracing::end_span(id);
+        }
+    }
+}
+
+/// Best-effort debug rendering of a traced argument, used by the
+/// `#[trace]` macro. A `Debug` impl that panics degrades to a
+/// `<unserializable>` placeholder so tracing itself never breaks the
+/// handler it's observing.
+pub fn serialize_arg<T: std::fmt::Debug>(name: &str, value: &T) -> String {
+    let rendered =
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| format!("{value:?}")))
+            .unwrap_or_else(|_| "<unserializable>".to_string());
+    format!("{name}={rendered}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_enabled_gates_on_the_configured_level() {
+        // `CURRENT_LEVEL` is a single process-wide static, so this is
+        // one test exercising every transition rather than several that
+        // could race each other under a parallel test runner.
+        set_level(Level::Off);
+        assert!(!is_enabled(Level::Error));
+        assert!(!is_enabled(Level::Trace));
+
+        set_level(Level::Warn);
+        assert!(is_enabled(Level::Error));
+        assert!(is_enabled(Level::Warn));
+        assert!(!is_enabled(Level::Info));
+
+        set_level(Level::Trace);
+        assert!(is_enabled(Level::Trace));
+
+        set_level(Level::Off);
+    }
+}
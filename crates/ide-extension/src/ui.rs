@@ -0,0 +1,29 @@
+//! Blocking selection and text-entry primitives a command handler can
+//! use to gather input instead of only firing a fixed notification.
+//!
+//! Extension calls are strictly request/response, so these host
+//! functions hold the calling handler open until the user responds. A
+//! dismissed prompt returns `Ok(None)` rather than an error so
+//! "the user cancelled" stays distinguishable from a real failure.
+
+use crate::bindings::ide::extension::ui;
+pub use crate::bindings::ide::extension::ui::{QuickPickItem, QuickPickOptions};
+
+/// Show a selection menu and block until the user picks an item or
+/// dismisses it.
+pub fn quick_pick(
+    items: Vec<QuickPickItem>,
+    options: QuickPickOptions,
+) -> Result<Option<QuickPickItem>, String> {
+    ui::quick_pick(&items, &options)
+}
+
+/// Show a single-line input box and block until the user submits a value
+/// or dismisses it. Set `password` to mask the typed characters.
+pub fn input_box(
+    prompt: &str,
+    placeholder: Option<&str>,
+    password: bool,
+) -> Result<Option<String>, String> {
+    ui::input_box(prompt, placeholder, password)
+}
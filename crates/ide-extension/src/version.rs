@@ -0,0 +1,13 @@
+//! API version this build of `ide_extension` implements.
+
+/// The API version exported by this crate, in the same
+/// `major.minor.patch` form as
+/// [`ActivationEvent::host_api_version`](crate::ActivationEvent) and
+/// `#[min_api_version]`. This crate does not compare it with either; the
+/// host's negotiation uses its own version and the declared minimum.
+pub const API_VERSION: &str = "0.3.0";
+
+/// Returns [`API_VERSION`].
+pub fn api_version() -> &'static str {
+    API_VERSION
+}
@@ -14,6 +14,7 @@ impl Extension for HelloWorld {
     fn activate(event: ActivationEvent) -> Result<(), String> {
         // Log activation
         logging::info("Hello World extension activated!");
+        tracing::set_level(event.tracing_level);
 
         // Register our command handler
         commands::register_command(CommandDefinition {
@@ -36,14 +37,18 @@ impl Extension for HelloWorld {
 #[export]
 impl CommandHandler for HelloWorld {
     /// Handle command execution.
+    #[trace]
     fn handle_command(
         command_id: &str,
         _args: Vec<CommandArg>,
     ) -> Result<Option<CommandArg>, String> {
         match command_id {
             "helloWorld.sayHello" => {
-                // Show a notification
-                notifications::show_info("Hello from WebAssembly!")?;
+                // Ask who to greet, falling back to a default if the
+                // user dismisses the prompt.
+                let name = ui::input_box("Who should we greet?", Some("World"), false)?
+                    .unwrap_or_else(|| "World".to_string());
+                notifications::show_info(&format!("Hello, {name}, from WebAssembly!"))?;
                 Ok(None)
             }
             _ => Err(format!("Unknown command: {}", command_id)),
@@ -53,3 +58,7 @@ impl CommandHandler for HelloWorld {
 
 /// The extension struct (can hold state if needed)
 struct HelloWorld;
+
+// Wire up the component's exports once `HelloWorld` implements every
+// capability trait it declared with `#[export]` above.
+export_extension!(HelloWorld);